@@ -32,14 +32,14 @@ mod slide_left {
     pub const OUT: fn(&mut egui::Ui, f32) = |ui, normal| {
         ui.ctx().set_transform_layer(
             ui.layer_id(),
-            TSTransform::from_translation((normal as f32 * -SLIDE_DISTANCE, 0.0).into()),
+            TSTransform::from_translation((normal * -SLIDE_DISTANCE, 0.0).into()),
         );
     };
     pub const IN: fn(&mut egui::Ui, f32) = |ui, normal| {
         ui.ctx().set_transform_layer(
             ui.layer_id(),
             TSTransform::from_translation(
-                (SLIDE_DISTANCE + normal as f32 * -SLIDE_DISTANCE, 0.0).into(),
+                (SLIDE_DISTANCE + normal * -SLIDE_DISTANCE, 0.0).into(),
             ),
         );
     };
@@ -51,14 +51,14 @@ mod slide_right {
     pub const OUT: fn(&mut egui::Ui, f32) = |ui, normal| {
         ui.ctx().set_transform_layer(
             ui.layer_id(),
-            TSTransform::from_translation((normal as f32 * SLIDE_DISTANCE, 0.0).into()),
+            TSTransform::from_translation((normal * SLIDE_DISTANCE, 0.0).into()),
         );
     };
     pub const IN: fn(&mut egui::Ui, f32) = |ui, normal| {
         ui.ctx().set_transform_layer(
             ui.layer_id(),
             TSTransform::from_translation(
-                (-SLIDE_DISTANCE + normal as f32 * SLIDE_DISTANCE, 0.0).into(),
+                (-SLIDE_DISTANCE + normal * SLIDE_DISTANCE, 0.0).into(),
             ),
         );
     };
@@ -146,7 +146,7 @@ mod fade_red {
         IN(ui, 1.0 - normal);
     };
     pub const IN: fn(&mut egui::Ui, f32) = |ui, normal| {
-        let inverse_normal = 1.0 - normal as f32;
+        let inverse_normal = 1.0 - normal;
 
         let mut text_color = ui.visuals_mut().text_color();
         let red_color_range = (255 - text_color[0]) as f32;
@@ -264,13 +264,17 @@ impl Default for ExampleApp {
 
 impl ExampleApp {
     /// Create an `Animation` from given configuration.
-    fn into_anim(&self) -> Animation {
+    fn to_anim(&self) -> Animation {
         let out_seg = AnimationSegment {
+            delay: 0.0,
             duration: self.out_dur,
+            easing: egui_animate::Easing::Linear,
             anim_fn: self.out_anim.out_fn(),
         };
         let in_seg = AnimationSegment {
+            delay: 0.0,
             duration: self.in_dur,
+            easing: egui_animate::Easing::Linear,
             anim_fn: self.in_anim.in_fn(),
         };
         Animation::from_segments(out_seg, in_seg)
@@ -303,7 +307,7 @@ impl eframe::App for ExampleApp {
                 ui,
                 "int_anim",
                 self.value_state,
-                self.into_anim(),
+                self.to_anim(),
                 |ui, value| {
                     let text = RichText::new(format!("Int: {}", value)).size(48.0);
                     ui.label(text);
@@ -316,10 +320,10 @@ impl eframe::App for ExampleApp {
 
                     ui.horizontal(|ui| {
                         if ui.button("Decrement").clicked() {
-                            self.value_state = value.checked_sub(1).unwrap_or(0);
+                            self.value_state = value.saturating_sub(1);
                         };
                         if ui.button("Increment").clicked() {
-                            self.value_state = value.checked_add(1).unwrap_or(u8::MAX);
+                            self.value_state = value.saturating_add(1);
                         };
                     });
                 },
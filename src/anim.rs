@@ -1,8 +1,32 @@
-/// An animation defined by out-in [`AnimationSegment`](s).
+use crate::{Direction, Easing, Repeat};
+
+/// Which value a [`TimelineSegment`] scopes: the prior value leaving, or the new
+/// value entering.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Scope {
+    /// The segment animates the value being replaced.
+    Out,
+    /// The segment animates the value replacing it.
+    In,
+}
+
+/// A single entry in an [`Animation`]'s timeline: an [`AnimationSegment`] plus
+/// which value ([`Scope`]) it animates.
+#[derive(Clone, Copy)]
+pub struct TimelineSegment {
+    /// Which value this segment scopes.
+    pub scope: Scope,
+    /// The segment's timing, easing and animation function.
+    pub segment: AnimationSegment,
+}
+
+/// An animation defined by an ordered timeline of [`TimelineSegment`]s.
 ///
-/// An animation must include either an *out* function, an *in* function, or both.
-/// Single function animations may be suitable for displaying or hiding elements,
-/// while out/in animations simplify transitions.
+/// An animation must include either an *out* segment, an *in* segment, or both.
+/// Single-segment animations may be suitable for displaying or hiding elements,
+/// while out/in animations simplify transitions. Beyond the simple two-segment
+/// case, a timeline can stage any number of segments (e.g. fade-out, then
+/// clip-collapse, then clip-expand, then slide-in) to build compound transitions.
 ///
 /// ## Example
 /// ```
@@ -35,22 +59,34 @@
 ///     ui.set_opacity(normal);
 /// };
 ///
-/// const FADE_ANIM: Animation = Animation::new(0.2, out_fn, in_fn);
+/// let fade_anim: Animation = Animation::new(0.2, out_fn, in_fn);
 /// ```
-#[derive(Default, Clone, Copy)]
+#[derive(Clone)]
 pub struct Animation {
-    /// The segment animating the prior value **out**.
-    pub out_seg: AnimationSegment,
-    /// The segment animating the new value **in**.
-    pub in_seg: AnimationSegment,
+    /// The ordered segments making up this animation.
+    timeline: Timeline,
+    /// How many times the timeline plays before stopping.
+    pub repeat: Repeat,
+    /// The direction the timeline plays in on successive cycles.
+    pub direction: Direction,
+}
+
+impl Default for Animation {
+    fn default() -> Self {
+        Animation::EMPTY
+    }
 }
 
 impl Animation {
     /// An empty placeholder animation.
-    pub const EMPTY: Animation =
-        Animation::from_segments(AnimationSegment::EMPTY, AnimationSegment::EMPTY);
+    pub const EMPTY: Animation = Animation {
+        timeline: Timeline::EMPTY,
+        repeat: Repeat::Once,
+        direction: Direction::Forward,
+    };
 
-    /// Create a new `Animation` with the given total `duration`, split over segments.
+    /// Create a new `Animation` with the given total `duration`, split over an
+    /// out segment and an in segment.
     pub const fn new(
         duration: f32,
         out_fn: fn(&mut egui::Ui, f32),
@@ -58,38 +94,145 @@ impl Animation {
     ) -> Self {
         let segment_duration = duration / 2.0;
 
-        let out_seg = AnimationSegment::new(segment_duration, out_fn);
-        let in_seg = AnimationSegment::new(segment_duration, in_fn);
-
-        Self { out_seg, in_seg }
+        Self::from_segments(
+            AnimationSegment::new(segment_duration, out_fn),
+            AnimationSegment::new(segment_duration, in_fn),
+        )
     }
 
     /// Create a new `Animation` with only the *out* segment. Passes the the prior
     /// value to the animation scope for the duration of the `out_fn`.
     pub const fn new_out(duration: f32, out_fn: fn(&mut egui::Ui, f32)) -> Self {
-        let out_seg = AnimationSegment::new(duration, out_fn);
-        let in_seg = AnimationSegment::EMPTY;
-
-        Self { out_seg, in_seg }
+        Self {
+            timeline: Timeline::one(TimelineSegment {
+                scope: Scope::Out,
+                segment: AnimationSegment::new(duration, out_fn),
+            }),
+            repeat: Repeat::Once,
+            direction: Direction::Forward,
+        }
     }
 
     /// Create a new `Animation` with only the *in* segment. Passes the the mutated
     /// value to the animation scope for the duration of the `in_fn`.
-    pub const fn new_in(duration: f32, out_fn: fn(&mut egui::Ui, f32)) -> Self {
-        let out_seg = AnimationSegment::EMPTY;
-        let in_seg = AnimationSegment::new(duration, out_fn);
-
-        Self { out_seg, in_seg }
+    pub const fn new_in(duration: f32, in_fn: fn(&mut egui::Ui, f32)) -> Self {
+        Self {
+            timeline: Timeline::one(TimelineSegment {
+                scope: Scope::In,
+                segment: AnimationSegment::new(duration, in_fn),
+            }),
+            repeat: Repeat::Once,
+            direction: Direction::Forward,
+        }
     }
 
-    /// Create a new `Animation` from the given [`AnimationSegment`]s.
+    /// Create a new `Animation` from an *out* and an *in* [`AnimationSegment`],
+    /// as a thin two-element timeline.
     pub const fn from_segments(out_seg: AnimationSegment, in_seg: AnimationSegment) -> Self {
-        Self { out_seg, in_seg }
+        Self {
+            timeline: Timeline::two(
+                TimelineSegment {
+                    scope: Scope::Out,
+                    segment: out_seg,
+                },
+                TimelineSegment {
+                    scope: Scope::In,
+                    segment: in_seg,
+                },
+            ),
+            repeat: Repeat::Once,
+            direction: Direction::Forward,
+        }
+    }
+
+    /// Create a new `Animation` from an explicit, ordered [`TimelineSegment`] list.
+    ///
+    /// Unlike [`Animation::new`]/[`Animation::from_segments`], this takes an
+    /// arbitrary-length `Vec` and so cannot be called from a `const` context.
+    pub fn from_timeline(timeline: Vec<TimelineSegment>) -> Self {
+        Self {
+            timeline: Timeline::Many(timeline),
+            ..Default::default()
+        }
+    }
+
+    /// Get the timeline's segments as a slice.
+    pub(crate) fn timeline(&self) -> &[TimelineSegment] {
+        self.timeline.as_slice()
+    }
+
+    /// Set how many times the timeline plays before stopping.
+    ///
+    /// Combine with [`Animation::with_direction`] to build idle attention-getters
+    /// (a gently pulsing button) or indeterminate busy indicators from the same
+    /// [`AnimationSegment`] primitives used for value-change transitions.
+    pub fn with_repeat(mut self, repeat: Repeat) -> Self {
+        self.repeat = repeat;
+        self
+    }
+
+    /// Set the direction the timeline plays in on successive cycles.
+    pub fn with_direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Get the total duration of a single pass of the animation, summed over
+    /// every segment's delay and duration.
+    pub fn duration(&self) -> f32 {
+        self.timeline()
+            .iter()
+            .map(|entry| entry.segment.delay + entry.segment.duration)
+            .sum()
+    }
+}
+
+/// An empty [`TimelineSegment`], used to pad [`Timeline::Inline`]'s unused slots.
+const EMPTY_ENTRY: TimelineSegment = TimelineSegment {
+    scope: Scope::Out,
+    segment: AnimationSegment::EMPTY,
+};
+
+/// Storage for an [`Animation`]'s timeline segments.
+///
+/// `new`, `new_out`, `new_in` and `from_segments` fit their segments inline
+/// (at most two), keeping them `const fn` so `const ANIM: Animation = ...`
+/// definitions still compile. [`Animation::from_timeline`] accepts an
+/// arbitrary-length `Vec` instead, which cannot be built in a `const` context.
+#[derive(Clone)]
+enum Timeline {
+    /// Up to two segments, stored inline. `len` is `0`, `1` or `2`; slots past
+    /// `len` hold [`EMPTY_ENTRY`] and are never read.
+    Inline { segments: [TimelineSegment; 2], len: u8 },
+    /// An arbitrary-length timeline, built via [`Animation::from_timeline`].
+    Many(Vec<TimelineSegment>),
+}
+
+impl Timeline {
+    const EMPTY: Timeline = Timeline::Inline {
+        segments: [EMPTY_ENTRY, EMPTY_ENTRY],
+        len: 0,
+    };
+
+    const fn one(entry: TimelineSegment) -> Self {
+        Timeline::Inline {
+            segments: [entry, EMPTY_ENTRY],
+            len: 1,
+        }
+    }
+
+    const fn two(first: TimelineSegment, second: TimelineSegment) -> Self {
+        Timeline::Inline {
+            segments: [first, second],
+            len: 2,
+        }
     }
 
-    /// Get the total duration of the animation.
-    pub const fn duration(&self) -> f32 {
-        self.out_seg.duration + self.in_seg.duration
+    fn as_slice(&self) -> &[TimelineSegment] {
+        match self {
+            Timeline::Inline { segments, len } => &segments[..*len as usize],
+            Timeline::Many(timeline) => timeline,
+        }
     }
 }
 
@@ -110,8 +253,15 @@ impl Animation {
 /// ```
 #[derive(Clone, Copy)]
 pub struct AnimationSegment {
+    /// The time to wait, in seconds, before the segment begins animating. Mirrors
+    /// CSS `transition-delay`; while waiting, the normal is held at `0.0` for a
+    /// [`Scope::In`] segment and at `1.0` for a [`Scope::Out`] segment (see
+    /// [`AnimationSegment::new_with_delay`]).
+    pub delay: f32,
     /// The duration of the animation, in seconds.
     pub duration: f32,
+    /// The easing curve applied to the normal before [`anim_fn`](Self::anim_fn) is called.
+    pub easing: Easing,
     /// The [`Ui`] mutating function for the given `f32` normal.
     pub anim_fn: fn(&mut egui::Ui, f32),
 }
@@ -125,18 +275,58 @@ impl Default for AnimationSegment {
 impl AnimationSegment {
     /// An empty placeholder animation segment.
     const EMPTY: AnimationSegment = AnimationSegment {
+        delay: 0.0,
         duration: 0.0,
+        easing: Easing::Linear,
         anim_fn: |_, _| {},
     };
 
     /// Create a new `AnimationSegment` from the given `duration` and `animation` function.
     pub const fn new(duration: f32, animation: fn(&mut egui::Ui, f32)) -> Self {
         Self {
+            delay: 0.0,
+            duration,
+            easing: Easing::Linear,
+            anim_fn: animation,
+        }
+    }
+
+    /// Create a new `AnimationSegment` that waits `delay` seconds before animating.
+    ///
+    /// This allows staggering a segment behind the rest of the [`Animation`], e.g.
+    /// an in-segment that holds the prior blank state briefly before fading the new
+    /// value in.
+    pub const fn new_with_delay(
+        delay: f32,
+        duration: f32,
+        animation: fn(&mut egui::Ui, f32),
+    ) -> Self {
+        Self {
+            delay,
             duration,
+            easing: Easing::Linear,
             anim_fn: animation,
         }
     }
 
+    /// Attach an [`Easing`] curve, applied to the normal before `anim_fn` is called.
+    pub const fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Get the normal for this segment at `local_elapsed` seconds into its window
+    /// (`delay` + `duration`), holding at `idle_normal` for the duration of `delay`.
+    pub(crate) fn normal_at(&self, local_elapsed: f32, idle_normal: f32) -> f32 {
+        if local_elapsed < self.delay {
+            idle_normal
+        } else if self.duration > 0.0 {
+            ((local_elapsed - self.delay) / self.duration).clamp(0.0, 1.0)
+        } else {
+            1.0
+        }
+    }
+
     /// Get the animation duration.
     pub fn duration(&self) -> f32 {
         self.duration
@@ -156,7 +346,8 @@ impl AnimationSegment {
         &mut self.anim_fn
     }
 
-    /// Apply the animation function, passing in the given `normal`.
+    /// Apply the animation function, passing in the given `normal` after applying
+    /// this segment's [`Easing`] curve.
     pub(super) fn animate<R>(
         &self,
         ui: &mut egui::Ui,
@@ -164,6 +355,7 @@ impl AnimationSegment {
         normal: f32,
         add_contents: impl FnOnce(&mut egui::Ui) -> R,
     ) -> R {
+        let normal = self.easing.apply(normal);
         Self::scope_animation(ui, id, |ui| (self.anim_fn)(ui, normal), add_contents)
     }
 
@@ -192,3 +384,45 @@ impl AnimationSegment {
         egui::LayerId::new(ui.layer_id().order, id)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn duration_includes_delays() {
+        let anim = Animation::from_segments(
+            AnimationSegment::new_with_delay(0.1, 0.2, |_, _| {}),
+            AnimationSegment::new_with_delay(0.3, 0.4, |_, _| {}),
+        );
+        assert_eq!(anim.duration(), 0.1 + 0.2 + 0.3 + 0.4);
+    }
+
+    #[test]
+    fn normal_at_holds_idle_value_during_delay() {
+        let seg = AnimationSegment::new_with_delay(0.5, 0.5, |_, _| {});
+        assert_eq!(seg.normal_at(0.0, 0.0), 0.0);
+        assert_eq!(seg.normal_at(0.25, 1.0), 1.0);
+        assert_eq!(seg.normal_at(0.75, 0.0), 0.5);
+        assert_eq!(seg.normal_at(1.0, 0.0), 1.0);
+    }
+
+    #[test]
+    fn duration_sums_an_arbitrary_timeline() {
+        let anim = Animation::from_timeline(vec![
+            TimelineSegment {
+                scope: Scope::Out,
+                segment: AnimationSegment::new(0.1, |_, _| {}),
+            },
+            TimelineSegment {
+                scope: Scope::Out,
+                segment: AnimationSegment::new(0.2, |_, _| {}),
+            },
+            TimelineSegment {
+                scope: Scope::In,
+                segment: AnimationSegment::new(0.3, |_, _| {}),
+            },
+        ]);
+        assert_eq!(anim.duration(), 0.1 + 0.2 + 0.3);
+    }
+}
@@ -0,0 +1,175 @@
+use crate::anim::AnimationSegment;
+
+/// Which components of a [`connected`] (shared-element) transition to animate.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ConnectedComponent {
+    /// Animate the horizontal offset between the prior and new position.
+    OffsetX,
+    /// Animate the vertical offset between the prior and new position.
+    OffsetY,
+    /// Animate the scale between the prior and new size.
+    Scale,
+    /// Cross-fade the opacity across the transition.
+    CrossFade,
+}
+
+/// All four [`ConnectedComponent`]s, for animating offset, scale and opacity together.
+pub const ALL_COMPONENTS: [ConnectedComponent; 4] = [
+    ConnectedComponent::OffsetX,
+    ConnectedComponent::OffsetY,
+    ConnectedComponent::Scale,
+    ConnectedComponent::CrossFade,
+];
+
+/// The state stored in [`egui`] memory for a [`connected`] widget, keyed by its `Id`.
+#[derive(Clone)]
+struct ConnectedState {
+    /// The widget's rect on the last frame it was seen.
+    rect: egui::Rect,
+    /// The rect prior to the most recent move, if any. `None` while the widget
+    /// is still settling in from its first appearance.
+    prior: Option<egui::Rect>,
+    /// The time, in seconds, at which `rect` last moved (or first appeared).
+    start: f64,
+}
+
+/// Derive the [`egui::emath::TSTransform`] that maps `new_rect`'s painted content
+/// toward `old_rect` at `normal == 0.0`, converging to the identity transform at
+/// `normal == 1.0`, for the selected `components`.
+pub fn connected_transform(
+    old_rect: egui::Rect,
+    new_rect: egui::Rect,
+    normal: f32,
+    components: &[ConnectedComponent],
+) -> egui::emath::TSTransform {
+    let scaling = if components.contains(&ConnectedComponent::Scale)
+        && new_rect.width() > 0.0
+        && new_rect.height() > 0.0
+    {
+        let old_scale =
+            (old_rect.width() / new_rect.width() + old_rect.height() / new_rect.height()) / 2.0;
+        (1.0 - normal) * old_scale + normal
+    } else {
+        1.0
+    };
+
+    let mut target_center = new_rect.center();
+    if components.contains(&ConnectedComponent::OffsetX) {
+        target_center.x = (1.0 - normal) * old_rect.center().x + normal * new_rect.center().x;
+    }
+    if components.contains(&ConnectedComponent::OffsetY) {
+        target_center.y = (1.0 - normal) * old_rect.center().y + normal * new_rect.center().y;
+    }
+
+    let translation = target_center.to_vec2() - scaling * new_rect.center().to_vec2();
+    egui::emath::TSTransform::new(translation, scaling)
+}
+
+/// Animate a widget between two layout positions (a shared-element transition),
+/// rather than fading content in place as [`crate::animate`] does.
+///
+/// Tracks `add_contents`'s [`egui::Rect`] across frames, keyed by a stable `Id`.
+/// When it moves, the selected `components` are tweened between the prior and
+/// new rect over `duration` seconds via [`connected_transform`]. On first
+/// appearance, with no prior rect to transition from, this falls back to a
+/// plain fade-in instead.
+pub fn connected<R>(
+    ui: &mut egui::Ui,
+    id_salt: impl std::hash::Hash,
+    duration: f32,
+    components: &[ConnectedComponent],
+    add_contents: impl FnOnce(&mut egui::Ui) -> R,
+) -> R {
+    let id = ui.make_persistent_id(id_salt);
+    let layer_id = AnimationSegment::animation_layer(ui, id);
+    let now = ui.input(|i| i.time);
+
+    let prior_state = ui.ctx().data_mut(|d| d.get_temp::<ConnectedState>(id));
+    // Whether this widget is still settling in from its first appearance, going
+    // by the state as of the last frame it was seen.
+    let is_appearance = prior_state.as_ref().is_none_or(|s| s.prior.is_none());
+    let fade_active = is_appearance || components.contains(&ConnectedComponent::CrossFade);
+
+    let speculative_elapsed = prior_state.as_ref().map_or(0.0, |s| (now - s.start) as f32);
+    let speculative_normal = if duration > 0.0 {
+        (speculative_elapsed / duration).clamp(0.0, 1.0)
+    } else {
+        1.0
+    };
+
+    let egui::InnerResponse { inner, response } = ui.scope_builder(
+        egui::UiBuilder::new().id_salt("connected_scope").layer_id(layer_id),
+        |ui| {
+            if fade_active {
+                ui.set_opacity(speculative_normal);
+            }
+            add_contents(ui)
+        },
+    );
+    let new_rect = response.rect;
+
+    let state = match prior_state {
+        Some(state) if state.rect == new_rect => state,
+        Some(state) => ConnectedState {
+            rect: new_rect,
+            prior: Some(state.rect),
+            start: now,
+        },
+        None => ConnectedState {
+            rect: new_rect,
+            prior: None,
+            start: now,
+        },
+    };
+    ui.ctx().data_mut(|d| d.insert_temp(id, state.clone()));
+
+    let elapsed = (now - state.start) as f32;
+    let finished = duration <= 0.0 || elapsed >= duration;
+
+    if !finished {
+        if let Some(prior_rect) = state.prior {
+            let normal = (elapsed / duration).clamp(0.0, 1.0);
+            let transform = connected_transform(prior_rect, new_rect, normal, components);
+            ui.ctx().set_transform_layer(layer_id, transform);
+        }
+        ui.ctx().request_repaint();
+    }
+
+    inner
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use egui::{Rect, pos2};
+
+    fn rect(min: (f32, f32), max: (f32, f32)) -> Rect {
+        Rect::from_min_max(pos2(min.0, min.1), pos2(max.0, max.1))
+    }
+
+    #[test]
+    fn transform_is_identity_at_normal_one() {
+        let old = rect((0.0, 0.0), (10.0, 10.0));
+        let new = rect((100.0, 100.0), (150.0, 140.0));
+        let transform = connected_transform(old, new, 1.0, &ALL_COMPONENTS);
+        assert!((transform.scaling - 1.0).abs() < 1e-4);
+        assert!(transform.translation.length() < 1e-3);
+    }
+
+    #[test]
+    fn transform_starts_at_old_center_at_normal_zero() {
+        let old = rect((0.0, 0.0), (10.0, 10.0));
+        let new = rect((100.0, 100.0), (110.0, 110.0));
+        let transform = connected_transform(old, new, 0.0, &ALL_COMPONENTS);
+        let mapped = transform.mul_pos(new.center());
+        assert!((mapped - old.center()).length() < 1e-3);
+    }
+
+    #[test]
+    fn unselected_components_snap_to_the_new_rect() {
+        let old = rect((0.0, 0.0), (10.0, 10.0));
+        let new = rect((100.0, 100.0), (150.0, 140.0));
+        let transform = connected_transform(old, new, 0.0, &[]);
+        assert_eq!(transform, egui::emath::TSTransform::IDENTITY);
+    }
+}
@@ -0,0 +1,158 @@
+use std::f32::consts::PI;
+
+/// A built-in easing curve, applied to a segment's normal before its `anim_fn`
+/// is called. Lets an animation function get a curve without the caller having
+/// to wrap it by hand (as `quadratic_in`/`quadratic_out` are in the examples).
+///
+/// All curves map `0.0` to `0.0` and `1.0` to `1.0`. The `InOut` variants mirror
+/// their `In` curve about `t = 0.5`.
+#[derive(Default, Clone, Copy, PartialEq, Debug)]
+pub enum Easing {
+    /// No easing; the normal is passed through unchanged.
+    #[default]
+    Linear,
+    /// Quadratic ease-in.
+    QuadIn,
+    /// Quadratic ease-out.
+    QuadOut,
+    /// Quadratic ease-in-out.
+    QuadInOut,
+    /// Back ease-in; overshoots below `0.0` before rising.
+    BackIn,
+    /// Back ease-out; overshoots above `1.0` before settling.
+    BackOut,
+    /// Bounce ease-out.
+    BounceOut,
+    /// Elastic ease-out.
+    ElasticOut,
+    /// Circular ease-in.
+    CircleIn,
+    /// Circular ease-out.
+    CircleOut,
+}
+
+impl Easing {
+    /// Apply the easing curve to `normal` (`0.0` to `1.0`).
+    pub fn apply(self, normal: f32) -> f32 {
+        match self {
+            Easing::Linear => normal,
+            Easing::QuadIn => quad_in(normal),
+            Easing::QuadOut => quad_out(normal),
+            Easing::QuadInOut => in_out(normal, quad_in),
+            Easing::BackIn => back_in(normal),
+            Easing::BackOut => back_out(normal),
+            Easing::BounceOut => bounce_out(normal),
+            Easing::ElasticOut => elastic_out(normal),
+            Easing::CircleIn => circle_in(normal),
+            Easing::CircleOut => circle_out(normal),
+        }
+    }
+}
+
+/// Mirror an `In` curve about `t = 0.5` to produce the `InOut` variant.
+fn in_out(t: f32, ease_in: fn(f32) -> f32) -> f32 {
+    if t < 0.5 {
+        ease_in(t * 2.0) / 2.0
+    } else {
+        1.0 - ease_in((1.0 - t) * 2.0) / 2.0
+    }
+}
+
+fn quad_in(t: f32) -> f32 {
+    t * t
+}
+
+fn quad_out(t: f32) -> f32 {
+    1.0 - quad_in(1.0 - t)
+}
+
+const BACK_S: f32 = 1.70158;
+
+fn back_in(t: f32) -> f32 {
+    t * t * ((BACK_S + 1.0) * t - BACK_S)
+}
+
+fn back_out(t: f32) -> f32 {
+    1.0 - back_in(1.0 - t)
+}
+
+fn bounce_out(t: f32) -> f32 {
+    let n1 = 7.5625;
+    let d1 = 2.75;
+
+    if t < 1.0 / d1 {
+        n1 * t * t
+    } else if t < 2.0 / d1 {
+        let t = t - 1.5 / d1;
+        n1 * t * t + 0.75
+    } else if t < 2.5 / d1 {
+        let t = t - 2.25 / d1;
+        n1 * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / d1;
+        n1 * t * t + 0.984375
+    }
+}
+
+fn elastic_out(t: f32) -> f32 {
+    if t <= 0.0 {
+        0.0
+    } else if t >= 1.0 {
+        1.0
+    } else {
+        2f32.powf(-10.0 * t) * ((t * 10.0 - 0.75) * (2.0 * PI / 3.0)).sin() + 1.0
+    }
+}
+
+fn circle_in(t: f32) -> f32 {
+    1.0 - (1.0 - t * t).sqrt()
+}
+
+fn circle_out(t: f32) -> f32 {
+    (1.0 - (t - 1.0).powi(2)).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_curves_map_endpoints() {
+        for easing in [
+            Easing::Linear,
+            Easing::QuadIn,
+            Easing::QuadOut,
+            Easing::QuadInOut,
+            Easing::BackIn,
+            Easing::BackOut,
+            Easing::BounceOut,
+            Easing::ElasticOut,
+            Easing::CircleIn,
+            Easing::CircleOut,
+        ] {
+            assert!(
+                (easing.apply(0.0)).abs() < 1e-4,
+                "{easing:?} did not map 0.0 to 0.0"
+            );
+            assert!(
+                (easing.apply(1.0) - 1.0).abs() < 1e-4,
+                "{easing:?} did not map 1.0 to 1.0"
+            );
+        }
+    }
+
+    #[test]
+    fn bounce_out_matches_piecewise_breakpoints() {
+        assert_eq!(bounce_out(0.0), 0.0);
+        assert!((bounce_out(1.0) - 1.0).abs() < 1e-6);
+
+        // Each piece touches 1.0 at its boundary and dips to its offset between.
+        let d1 = 2.75;
+        for t in [1.0 / d1, 2.0 / d1, 2.5 / d1] {
+            assert!((bounce_out(t) - 1.0).abs() < 1e-5);
+        }
+        assert!((bounce_out(1.5 / d1) - 0.75).abs() < 1e-5);
+        assert!((bounce_out(2.25 / d1) - 0.9375).abs() < 1e-5);
+        assert!((bounce_out(2.625 / d1) - 0.984375).abs() < 1e-5);
+    }
+}
@@ -0,0 +1,91 @@
+/// Interpolate a value between two endpoints for use with [`crate::animate_lerp`].
+///
+/// Uses the `(1.0 - normal) * from + normal * to` shape, where `normal` is the
+/// segment's eased normal as passed by [`crate::animate_lerp`]. Implementations must
+/// return exactly `to` at `normal == 1.0` so repeated animations don't accumulate
+/// rounding drift.
+pub trait AnimationLerp<T = Self> {
+    /// Interpolate between `from` and `to` at the given `normal` (`0.0` to `1.0`).
+    fn lerp(&self, from: &T, to: &T, normal: f32) -> T;
+}
+
+macro_rules! impl_float_lerp {
+    ($($ty:ty),*) => {
+        $(
+            impl AnimationLerp for $ty {
+                fn lerp(&self, from: &Self, to: &Self, normal: f32) -> Self {
+                    if normal >= 1.0 {
+                        *to
+                    } else {
+                        (1.0 - normal as $ty) * from + normal as $ty * to
+                    }
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_int_lerp {
+    ($($ty:ty),*) => {
+        $(
+            impl AnimationLerp for $ty {
+                fn lerp(&self, from: &Self, to: &Self, normal: f32) -> Self {
+                    if normal >= 1.0 {
+                        *to
+                    } else {
+                        let from = *from as f64;
+                        let to = *to as f64;
+                        ((1.0 - normal as f64) * from + normal as f64 * to).round() as $ty
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_float_lerp!(f32, f64);
+impl_int_lerp!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl AnimationLerp for egui::Color32 {
+    fn lerp(&self, from: &Self, to: &Self, normal: f32) -> Self {
+        if normal >= 1.0 {
+            return *to;
+        }
+
+        let lerp_channel = |from: u8, to: u8| -> u8 {
+            ((1.0 - normal) * from as f32 + normal * to as f32).round() as u8
+        };
+
+        egui::Color32::from_rgba_premultiplied(
+            lerp_channel(from.r(), to.r()),
+            lerp_channel(from.g(), to.g()),
+            lerp_channel(from.b(), to.b()),
+            lerp_channel(from.a(), to.a()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn float_lerp_reaches_exact_endpoints() {
+        assert_eq!(0.0_f32.lerp(&0.0, &10.0, 0.0), 0.0);
+        assert_eq!(0.0_f32.lerp(&0.0, &10.0, 1.0), 10.0);
+        assert_eq!(0.0_f32.lerp(&0.0, &10.0, 0.5), 5.0);
+    }
+
+    #[test]
+    fn int_lerp_rounds_and_reaches_exact_endpoint() {
+        assert_eq!(0_u8.lerp(&3, &7, 1.0), 7);
+        assert_eq!(0_i32.lerp(&3, &7, 0.5), 5);
+    }
+
+    #[test]
+    fn color_lerp_reaches_exact_endpoint() {
+        let from = egui::Color32::from_rgb(0, 0, 0);
+        let to = egui::Color32::from_rgb(200, 100, 50);
+        assert_eq!(from.lerp(&from, &to, 1.0), to);
+    }
+}
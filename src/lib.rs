@@ -0,0 +1,293 @@
+//! # egui_animate
+//!
+//! Animate a value change inside an [`egui::Ui`], running an out [`AnimationSegment`]
+//! on the prior value and an in [`AnimationSegment`] on the new value whenever it
+//! changes. See [`Animation`] for how to define an animation.
+
+mod anim;
+mod connected;
+mod easing;
+mod lerp;
+mod playback;
+
+pub use anim::{Animation, AnimationSegment, Scope, TimelineSegment};
+pub use connected::{connected, connected_transform, ConnectedComponent, ALL_COMPONENTS};
+pub use easing::Easing;
+pub use lerp::AnimationLerp;
+pub use playback::{Direction, Repeat};
+
+/// The animation state stored in [`egui`] memory, keyed by the `animate`/`animate_lerp`
+/// `id_salt`.
+#[derive(Clone)]
+struct AnimationState<T> {
+    /// The value prior to the most recent change, animated by `Scope::Out` segments.
+    prior: Option<T>,
+    /// The current value, animated by `Scope::In` segments.
+    value: T,
+    /// The time, in seconds, at which `value` last changed.
+    start: f64,
+}
+
+/// Update the stored [`AnimationState`] for `id`, recording a value change.
+fn track_state<T>(ui: &mut egui::Ui, id: egui::Id, value: &T) -> AnimationState<T>
+where
+    T: Clone + PartialEq + Send + Sync + 'static,
+{
+    let now = ui.input(|i| i.time);
+
+    let state = ui.ctx().data_mut(|d| d.get_temp::<AnimationState<T>>(id));
+    let state = match state {
+        Some(state) if &state.value == value => state,
+        Some(state) => AnimationState {
+            prior: Some(state.value),
+            value: value.clone(),
+            start: now,
+        },
+        None => AnimationState {
+            prior: None,
+            value: value.clone(),
+            start: now,
+        },
+    };
+
+    ui.ctx().data_mut(|d| d.insert_temp(id, state.clone()));
+    state
+}
+
+/// The timeline entry active at `elapsed` seconds, along with the normal local
+/// to that entry's window (`delay` + `duration`). Entries scoped `Scope::Out`
+/// are skipped entirely when there is no `prior` value to show, e.g. on first
+/// appearance, falling straight through to the first `Scope::In` entry.
+fn active_entry(
+    timeline: &[TimelineSegment],
+    elapsed: f32,
+    has_prior: bool,
+) -> Option<(&TimelineSegment, f32)> {
+    let mut cursor = 0.0;
+    for entry in timeline {
+        if entry.scope == Scope::Out && !has_prior {
+            continue;
+        }
+
+        let window = entry.segment.delay + entry.segment.duration;
+        if elapsed < cursor + window {
+            return Some((entry, elapsed - cursor));
+        }
+        cursor += window;
+    }
+    None
+}
+
+/// The clock state of an [`Animation`]'s playback.
+enum PlaybackClock {
+    /// Playback is in progress, at `local` seconds into the current cycle's
+    /// timeline, running `reversed` when [`Direction::PingPong`] lands on an
+    /// odd cycle.
+    Active { local: f32, reversed: bool },
+    /// Playback has permanently stopped (a finite [`Repeat`] has elapsed, or
+    /// the timeline is empty).
+    Stopped,
+}
+
+/// Advance `animation`'s playback clock to `elapsed` seconds since it started,
+/// honoring its [`Repeat`] count and [`Direction`].
+fn playback_clock(animation: &Animation, elapsed: f32) -> PlaybackClock {
+    let total = animation.duration();
+    if total <= 0.0 {
+        return PlaybackClock::Stopped;
+    }
+
+    let cycle = (elapsed / total).floor() as u32;
+    let stopped = match animation.repeat {
+        Repeat::Once => elapsed >= total,
+        Repeat::Count(n) => elapsed >= total * n as f32,
+        Repeat::Forever => false,
+    };
+    if stopped {
+        return PlaybackClock::Stopped;
+    }
+
+    let local = elapsed - cycle as f32 * total;
+    let reversed = animation.direction == Direction::PingPong && cycle % 2 == 1;
+    PlaybackClock::Active { local, reversed }
+}
+
+/// Animate a value change, running each [`TimelineSegment`] of the [`Animation`]
+/// in order, scoping the prior value for `Scope::Out` segments and the new value
+/// for `Scope::In` segments, whenever `value` differs from the last frame it was
+/// passed.
+///
+/// ## Example
+/// ```
+/// # use egui_animate::{Animation, animate};
+/// # const ANIM: Animation = Animation::new(
+/// #     0.2,
+/// #     |ui, normal| ui.set_opacity(1.0 - normal),
+/// #     |ui, normal| ui.set_opacity(normal),
+/// # );
+/// # egui::__run_test_ui(|ui| {
+/// animate(ui, "counter", 7, ANIM, |ui, value| {
+///     ui.label(format!("Value: {value}"));
+/// });
+/// # });
+/// ```
+pub fn animate<T, R>(
+    ui: &mut egui::Ui,
+    id_salt: impl std::hash::Hash,
+    value: T,
+    animation: Animation,
+    add_contents: impl FnOnce(&mut egui::Ui, T) -> R,
+) -> R
+where
+    T: Clone + PartialEq + Send + Sync + 'static,
+{
+    let id = ui.make_persistent_id(id_salt);
+    let state = track_state(ui, id, &value);
+    let elapsed = (ui.input(|i| i.time) - state.start) as f32;
+
+    let local = match playback_clock(&animation, elapsed) {
+        PlaybackClock::Active { local, reversed } if reversed => animation.duration() - local,
+        PlaybackClock::Active { local, .. } => local,
+        PlaybackClock::Stopped => return add_contents(ui, value),
+    };
+
+    match active_entry(animation.timeline(), local, state.prior.is_some()) {
+        Some((entry, local_elapsed)) => {
+            let idle_normal = if entry.scope == Scope::Out { 1.0 } else { 0.0 };
+            let normal = entry.segment.normal_at(local_elapsed, idle_normal);
+            let shown = match entry.scope {
+                Scope::Out => state.prior.unwrap_or(value),
+                Scope::In => value,
+            };
+
+            ui.ctx().request_repaint();
+            entry
+                .segment
+                .animate(ui, id, normal, |ui| add_contents(ui, shown))
+        }
+        None => add_contents(ui, value),
+    }
+}
+
+/// Animate a value *change*, feeding an interpolated value into `add_contents`
+/// during `Scope::In` segments rather than snapping straight to the new value.
+///
+/// `Scope::Out` segments behave as in [`animate`], scoping the prior value
+/// unmodified. During a `Scope::In` segment, `value` is produced by lerping
+/// from the prior value to the new value as the segment's normal advances, via
+/// [`AnimationLerp`]. This lets a counter count up smoothly, or a color
+/// cross-fade, instead of only fading the container that holds it.
+///
+/// ## Example
+/// ```
+/// # use egui_animate::{Animation, animate_lerp};
+/// # const ANIM: Animation = Animation::new(
+/// #     0.2,
+/// #     |ui, normal| ui.set_opacity(1.0 - normal),
+/// #     |ui, normal| ui.set_opacity(normal),
+/// # );
+/// # egui::__run_test_ui(|ui| {
+/// animate_lerp(ui, "counter", 7, ANIM, |ui, value| {
+///     ui.label(format!("Value: {value}"));
+/// });
+/// # });
+/// ```
+pub fn animate_lerp<T, R>(
+    ui: &mut egui::Ui,
+    id_salt: impl std::hash::Hash,
+    value: T,
+    animation: Animation,
+    add_contents: impl FnOnce(&mut egui::Ui, T) -> R,
+) -> R
+where
+    T: AnimationLerp<T> + Clone + PartialEq + Send + Sync + 'static,
+{
+    let id = ui.make_persistent_id(id_salt);
+    let state = track_state(ui, id, &value);
+    let elapsed = (ui.input(|i| i.time) - state.start) as f32;
+
+    let local = match playback_clock(&animation, elapsed) {
+        PlaybackClock::Active { local, reversed } if reversed => animation.duration() - local,
+        PlaybackClock::Active { local, .. } => local,
+        PlaybackClock::Stopped => return add_contents(ui, value),
+    };
+
+    match active_entry(animation.timeline(), local, state.prior.is_some()) {
+        Some((entry, local_elapsed)) => {
+            let idle_normal = if entry.scope == Scope::Out { 1.0 } else { 0.0 };
+            let normal = entry.segment.normal_at(local_elapsed, idle_normal);
+
+            ui.ctx().request_repaint();
+            match entry.scope {
+                Scope::Out => {
+                    let shown = state.prior.unwrap_or_else(|| value.clone());
+                    entry
+                        .segment
+                        .animate(ui, id, normal, |ui| add_contents(ui, shown))
+                }
+                Scope::In => {
+                    let from = state.prior.unwrap_or_else(|| value.clone());
+                    let eased = entry.segment.easing.apply(normal);
+                    let lerped = value.lerp(&from, &value, eased);
+                    entry
+                        .segment
+                        .animate(ui, id, normal, |ui| add_contents(ui, lerped))
+                }
+            }
+        }
+        None => add_contents(ui, value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn anim_with(repeat: Repeat, direction: Direction) -> Animation {
+        Animation::new_in(1.0, |_, _| {})
+            .with_repeat(repeat)
+            .with_direction(direction)
+    }
+
+    #[test]
+    fn once_stops_after_a_single_pass() {
+        let anim = anim_with(Repeat::Once, Direction::Forward);
+        assert!(matches!(
+            playback_clock(&anim, 0.5),
+            PlaybackClock::Active { .. }
+        ));
+        assert!(matches!(playback_clock(&anim, 1.5), PlaybackClock::Stopped));
+    }
+
+    #[test]
+    fn count_stops_after_n_passes() {
+        let anim = anim_with(Repeat::Count(2), Direction::Forward);
+        assert!(matches!(
+            playback_clock(&anim, 1.5),
+            PlaybackClock::Active { .. }
+        ));
+        assert!(matches!(playback_clock(&anim, 2.5), PlaybackClock::Stopped));
+    }
+
+    #[test]
+    fn forever_never_stops() {
+        let anim = anim_with(Repeat::Forever, Direction::Forward);
+        assert!(matches!(
+            playback_clock(&anim, 100.5),
+            PlaybackClock::Active { .. }
+        ));
+    }
+
+    #[test]
+    fn ping_pong_reverses_on_odd_cycles() {
+        let anim = anim_with(Repeat::Forever, Direction::PingPong);
+        match playback_clock(&anim, 0.5) {
+            PlaybackClock::Active { reversed, .. } => assert!(!reversed),
+            PlaybackClock::Stopped => panic!("expected active playback"),
+        }
+        match playback_clock(&anim, 1.5) {
+            PlaybackClock::Active { reversed, .. } => assert!(reversed),
+            PlaybackClock::Stopped => panic!("expected active playback"),
+        }
+    }
+}
@@ -0,0 +1,22 @@
+/// How many times an [`crate::Animation`] plays before stopping.
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Repeat {
+    /// Play through the timeline once, then hold the final value.
+    #[default]
+    Once,
+    /// Play through the timeline `n` times, then hold the final value.
+    Count(u32),
+    /// Loop indefinitely.
+    Forever,
+}
+
+/// The direction an [`crate::Animation`] plays in on successive cycles.
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Direction {
+    /// Always play the timeline forward.
+    #[default]
+    Forward,
+    /// Alternate forward and backward on successive cycles, so the animation
+    /// bounces back and forth rather than snapping back to the start.
+    PingPong,
+}